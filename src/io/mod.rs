@@ -1,9 +1,18 @@
 //! Sub-readers and writers for [std::io].
+//!
+//! With the `tokio` feature enabled, [SubReader] and [SubWriter] also implement the
+//! corresponding `tokio::io` async traits.
+mod multi;
+mod pos_read;
 mod read;
+mod tee;
 mod write;
 use std::io;
 
+pub use multi::{MultiSubReader, Segment};
+pub use pos_read::{PosRead, PosSubReader};
 pub use read::SubReader;
+pub use tee::TeeSubReader;
 pub use write::SubWriter;
 
 fn map_seek_oob(maybe_pos: Option<u64>) -> io::Result<u64> {
@@ -0,0 +1,285 @@
+use super::{map_seek_oob, seek_oob};
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+/// One `[inner_offset, inner_offset + len)` window of the inner reader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub inner_offset: u64,
+    pub len: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IndexedSegment {
+    segment: Segment,
+    virtual_start: u64,
+}
+
+/// Presents several non-contiguous windows of a single inner [Read]`+`[Seek] as one logical
+/// contiguous stream, without copying.
+///
+/// Useful for reading a record split across a file (e.g. a header region plus a data region)
+/// as if it were one stream. Gaps between segments and out-of-order segments are allowed.
+/// A single `read` never crosses a segment boundary, matching std's own `Read` guidance.
+#[derive(Debug, Clone)]
+pub struct MultiSubReader<R> {
+    inner: R,
+    segments: Vec<IndexedSegment>,
+    total_len: u64,
+    current: usize,
+    within: u64,
+    /// Whether `inner` is known to already be positioned at `inner_offset + within` of the
+    /// current segment.
+    seeked: bool,
+}
+
+impl<R> MultiSubReader<R> {
+    /// Creates a new MultiSubReader over the given segments, in the order given.
+    pub fn new(inner: R, segments: impl IntoIterator<Item = Segment>) -> Self {
+        let mut virtual_start = 0;
+        let segments: Vec<IndexedSegment> = segments
+            .into_iter()
+            .map(|segment| {
+                let indexed = IndexedSegment {
+                    segment,
+                    virtual_start,
+                };
+                virtual_start += segment.len;
+                indexed
+            })
+            .collect();
+        let total_len = virtual_start;
+        MultiSubReader {
+            inner,
+            segments,
+            total_len,
+            current: 0,
+            within: 0,
+            seeked: false,
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// The total length of the logical stream, i.e. the sum of all segment lengths.
+    pub fn total_len(&self) -> u64 {
+        self.total_len
+    }
+
+    fn virtual_pos(&self) -> u64 {
+        match self.segments.get(self.current) {
+            Some(seg) => seg.virtual_start + self.within,
+            None => self.total_len,
+        }
+    }
+
+    /// Finds the segment (and offset within it) owning a given virtual position, by binary
+    /// search over the cumulative virtual-start table.
+    fn locate(&self, virtual_pos: u64) -> (usize, u64) {
+        if virtual_pos >= self.total_len {
+            return (self.segments.len(), 0);
+        }
+        let idx = self
+            .segments
+            .partition_point(|seg| seg.virtual_start + seg.segment.len <= virtual_pos);
+        let within = virtual_pos - self.segments[idx].virtual_start;
+        (idx, within)
+    }
+}
+
+impl<R: Seek> MultiSubReader<R> {
+    /// Seeks the inner reader past any exhausted segments, then (if not already known to be
+    /// positioned there) to `inner_offset + within` of the current segment.
+    fn ensure_positioned(&mut self) -> io::Result<()> {
+        while self
+            .segments
+            .get(self.current)
+            .is_some_and(|seg| self.within >= seg.segment.len)
+        {
+            self.current += 1;
+            self.within = 0;
+            self.seeked = false;
+        }
+        if !self.seeked {
+            if let Some(seg) = self.segments.get(self.current) {
+                self.inner
+                    .seek(SeekFrom::Start(seg.segment.inner_offset + self.within))?;
+            }
+            self.seeked = true;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> Read for MultiSubReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.ensure_positioned()?;
+        let Some(seg) = self.segments.get(self.current) else {
+            return Ok(0);
+        };
+        let remaining = seg.segment.len - self.within;
+        let to_read = std::cmp::min(buf.len() as u64, remaining) as usize;
+        let bytes_read = self.inner.read(&mut buf[..to_read])?;
+        self.within += bytes_read as u64;
+        Ok(bytes_read)
+    }
+}
+
+impl<R: BufRead + Seek> BufRead for MultiSubReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.ensure_positioned()?;
+        let Some(seg) = self.segments.get(self.current) else {
+            return Ok(&[]);
+        };
+        let remaining = (seg.segment.len - self.within) as usize;
+        let buf = self.inner.fill_buf()?;
+        Ok(&buf[..std::cmp::min(buf.len(), remaining)])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let Some(seg) = self.segments.get(self.current) else {
+            return;
+        };
+        let remaining = seg.segment.len - self.within;
+        let to_consume = (amt as u64).min(remaining);
+        self.inner.consume(to_consume as usize);
+        self.within += to_consume;
+    }
+}
+
+impl<R: Read + Seek> Seek for MultiSubReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let current_virtual = self.virtual_pos();
+        let new_virtual = match pos {
+            SeekFrom::Start(offset) => offset,
+            SeekFrom::End(offset) => map_seek_oob(self.total_len.checked_add_signed(offset))?,
+            SeekFrom::Current(offset) => map_seek_oob(current_virtual.checked_add_signed(offset))?,
+        };
+        if new_virtual > self.total_len {
+            return seek_oob();
+        }
+        let (idx, within) = self.locate(new_virtual);
+        if let Some(seg) = self.segments.get(idx) {
+            self.inner
+                .seek(SeekFrom::Start(seg.segment.inner_offset + within))?;
+        }
+        self.current = idx;
+        self.within = within;
+        self.seeked = true;
+        Ok(new_virtual)
+    }
+
+    /// Infallible.
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.virtual_pos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_multi_subreader_contiguous_read() {
+        let data: Vec<u8> = (0..20).collect();
+        let cursor = Cursor::new(data);
+        let mut reader = MultiSubReader::new(
+            cursor,
+            [
+                Segment {
+                    inner_offset: 0,
+                    len: 3,
+                },
+                Segment {
+                    inner_offset: 10,
+                    len: 4,
+                },
+            ],
+        );
+        let mut buf = Vec::default();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(&buf, &[0, 1, 2, 10, 11, 12, 13]);
+    }
+
+    #[test]
+    fn test_multi_subreader_seek() {
+        let data: Vec<u8> = (0..20).collect();
+        let cursor = Cursor::new(data);
+        let mut reader = MultiSubReader::new(
+            cursor,
+            [
+                Segment {
+                    inner_offset: 0,
+                    len: 3,
+                },
+                Segment {
+                    inner_offset: 10,
+                    len: 4,
+                },
+            ],
+        );
+
+        reader.seek(SeekFrom::Start(4)).unwrap();
+        let mut buf = [0u8; 2];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [11, 12]);
+
+        assert_eq!(reader.seek(SeekFrom::End(0)).unwrap(), 7);
+        let mut buf = Vec::default();
+        reader.read_to_end(&mut buf).unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_multi_subreader_out_of_order_first_segment() {
+        let data: Vec<u8> = (0..20).collect();
+        let cursor = Cursor::new(data);
+        let mut reader = MultiSubReader::new(
+            cursor,
+            [
+                Segment {
+                    inner_offset: 10,
+                    len: 4,
+                },
+                Segment {
+                    inner_offset: 0,
+                    len: 3,
+                },
+            ],
+        );
+        let mut buf = Vec::default();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(&buf, &[10, 11, 12, 13, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_multi_subreader_bufread_fill_and_consume() {
+        let data: Vec<u8> = (0..10).collect();
+        let cursor = Cursor::new(data);
+        let mut reader = MultiSubReader::new(
+            cursor,
+            [Segment {
+                inner_offset: 0,
+                len: 10,
+            }],
+        );
+
+        let mut buf = Vec::default();
+        loop {
+            let available = reader.fill_buf().unwrap();
+            if available.is_empty() {
+                break;
+            }
+            let n = available.len().min(4);
+            buf.extend_from_slice(&available[..n]);
+            reader.consume(n);
+        }
+        assert_eq!(buf, (0..10).collect::<Vec<u8>>());
+    }
+}
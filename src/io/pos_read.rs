@@ -0,0 +1,169 @@
+use super::map_seek_oob;
+use std::{
+    borrow::Borrow,
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    ops::Deref,
+};
+
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+#[cfg(windows)]
+use std::os::windows::fs::FileExt;
+
+/// Like [Read], but reads from an explicit `offset` without moving any shared cursor.
+///
+/// Implemented for anything that [Borrow]s a [File] (e.g. `&File`, `Arc<File>`), so many
+/// instances can view the same open file concurrently without contending over its position.
+///
+/// On Unix this delegates to `pread`, which truly never touches the file's cursor. On Windows
+/// it delegates to `seek_read`, which *does* move the shared cursor as a side effect;
+/// concurrent `read_at` calls on the same `File` handle from multiple threads can therefore
+/// race with each other on Windows, even though each call's own data is read from the right
+/// offset.
+pub trait PosRead {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize>;
+}
+
+impl<P: Borrow<File>> PosRead for P {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        #[cfg(unix)]
+        {
+            FileExt::read_at(self.borrow(), buf, offset)
+        }
+        #[cfg(windows)]
+        {
+            FileExt::seek_read(self.borrow(), buf, offset)
+        }
+    }
+}
+
+/// Implements [Read] and [Seek] for a portion of a [PosRead] inner type, using positioned
+/// reads so the inner's file cursor is never touched.
+///
+/// Unlike [SubReader](super::SubReader), `Seek` is pure arithmetic on the instance-local `pos`
+/// and never issues a syscall, so many `PosSubReader`s (e.g. over `Arc<File>`) can view disjoint
+/// or overlapping windows of the same open file at once, including from different threads.
+/// **On Unix** this concurrency is race-free; on Windows, see the caveat on [PosRead].
+#[derive(Debug, Clone)]
+pub struct PosSubReader<P> {
+    inner: P,
+    start: u64,
+    end: u64,
+    pos: u64,
+}
+
+impl<P: PosRead> Read for PosSubReader<P> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.end - self.start;
+        if self.pos >= len {
+            return Ok(0);
+        }
+        let max_read = (len - self.pos) as usize;
+        let to_read = std::cmp::min(buf.len(), max_read);
+        let bytes_read = self.inner.read_at(&mut buf[..to_read], self.start + self.pos)?;
+        self.pos += bytes_read as u64;
+        Ok(bytes_read)
+    }
+}
+
+impl<P> Deref for PosSubReader<P> {
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<P> PosSubReader<P> {
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Creates a new PosSubReader starting at `start` and spanning `length` bytes.
+    ///
+    /// No seek is needed since reads never touch the inner's cursor.
+    pub fn new_at(inner: P, start: u64, length: u64) -> Self {
+        PosSubReader {
+            inner,
+            start,
+            end: start + length,
+            pos: 0,
+        }
+    }
+}
+
+impl<P> Seek for PosSubReader<P> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let len = self.end - self.start;
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => offset,
+            SeekFrom::End(offset) => map_seek_oob(len.checked_add_signed(offset))?,
+            SeekFrom::Current(offset) => map_seek_oob(self.pos.checked_add_signed(offset))?,
+        };
+        // Seeking past the end is allowed, matching `std::io::Seek` and `SubReader`; a
+        // subsequent `read` at an out-of-range `pos` simply returns 0.
+        self.pos = new_pos;
+        self.stream_position()
+    }
+
+    /// Infallible.
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Writes `data` to a fresh file under the system temp dir and reopens it read-only.
+    fn tempfile_with(name: &str, data: &[u8]) -> File {
+        let path = std::env::temp_dir().join(format!("subio-pos-read-test-{name}-{}", std::process::id()));
+        std::fs::write(&path, data).unwrap();
+        File::open(&path).unwrap()
+    }
+
+    #[test]
+    fn test_pos_subreader() {
+        let data: Vec<u8> = (0..10).collect();
+        let file = Arc::new(tempfile_with("basic", &data));
+        let mut subreader = PosSubReader::new_at(file, 3, 5);
+        let mut buf = Vec::default();
+        subreader.read_to_end(&mut buf).unwrap();
+        assert_eq!(&buf, &[3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_pos_subreader_concurrent() {
+        let data: Vec<u8> = (0..20).collect();
+        let file = Arc::new(tempfile_with("concurrent", &data));
+
+        let mut a = PosSubReader::new_at(Arc::clone(&file), 0, 5);
+        let mut b = PosSubReader::new_at(Arc::clone(&file), 10, 5);
+
+        let mut buf_a = [0u8; 5];
+        let mut buf_b = [0u8; 5];
+        a.read_exact(&mut buf_a).unwrap();
+        b.read_exact(&mut buf_b).unwrap();
+
+        assert_eq!(buf_a, [0, 1, 2, 3, 4]);
+        assert_eq!(buf_b, [10, 11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn test_pos_subreader_seek_past_end() {
+        let data: Vec<u8> = (0..10).collect();
+        let file = Arc::new(tempfile_with("seek_past_end", &data));
+        let mut subreader = PosSubReader::new_at(file, 0, 5);
+
+        assert_eq!(subreader.seek(SeekFrom::Start(100)).unwrap(), 100);
+        let mut buf = [0u8; 1];
+        assert_eq!(subreader.read(&mut buf).unwrap(), 0);
+    }
+}
@@ -4,6 +4,14 @@ use std::{
     ops::Deref,
 };
 
+#[cfg(feature = "tokio")]
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, ReadBuf};
+
 /// Implements [Read] and [Seek] for a portion of the inner type,
 /// where that inner type implements those traits.
 ///
@@ -16,6 +24,9 @@ pub struct SubReader<R> {
     start: u64,
     end: u64,
     pos: u64,
+    /// Target of an in-flight [AsyncSeek], set by `start_seek` and consumed by `poll_complete`.
+    #[cfg(feature = "tokio")]
+    pending_seek: Option<u64>,
 }
 
 impl<R: Read> Read for SubReader<R> {
@@ -52,6 +63,11 @@ impl<R> SubReader<R> {
         self.pos
     }
 
+    /// Current position relative to `start`, without requiring `R: Seek`.
+    pub(crate) fn relative_pos(&self) -> u64 {
+        self.pos - self.start
+    }
+
     pub fn new_unchecked(inner: R, pos: u64, length: u64) -> Self {
         let start = pos;
         let end = start + length;
@@ -60,6 +76,8 @@ impl<R> SubReader<R> {
             start,
             end,
             pos,
+            #[cfg(feature = "tokio")]
+            pending_seek: None,
         }
     }
 }
@@ -122,6 +140,80 @@ impl<R: Seek> Seek for SubReader<R> {
     }
 }
 
+#[cfg(feature = "tokio")]
+impl<R: AsyncRead + Unpin> AsyncRead for SubReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.pos >= self.end {
+            return Poll::Ready(Ok(()));
+        }
+        let max_read = (self.end - self.pos) as usize;
+        let mut limited = buf.take(max_read);
+        ready!(Pin::new(&mut self.inner).poll_read(cx, &mut limited))?;
+        let n = limited.filled().len();
+        unsafe {
+            buf.assume_init(n);
+        }
+        buf.advance(n);
+        self.pos += n as u64;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncBufRead + Unpin> AsyncBufRead for SubReader<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.pos >= this.end {
+            return Poll::Ready(Ok(&[]));
+        }
+        let max_len = (this.end - this.pos) as usize;
+        let buf = ready!(Pin::new(&mut this.inner).poll_fill_buf(cx))?;
+        Poll::Ready(Ok(&buf[..std::cmp::min(buf.len(), max_len)]))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        let remaining = this.end.saturating_sub(this.pos);
+        let to_consume = amt.min(remaining as usize);
+        Pin::new(&mut this.inner).consume(to_consume);
+        this.pos += to_consume as u64;
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncSeek + Unpin> AsyncSeek for SubReader<R> {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+        let new_pos = match position {
+            SeekFrom::Start(offset) => this.start + offset,
+            SeekFrom::End(offset) => map_seek_oob(this.end.checked_add_signed(offset))?,
+            SeekFrom::Current(offset) => map_seek_oob(this.pos.checked_add_signed(offset))?,
+        };
+        if new_pos < this.start {
+            return seek_oob();
+        }
+        let relative = (new_pos as i64).checked_sub(this.pos as i64).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Seek position out of bounds")
+        })?;
+        Pin::new(&mut this.inner).start_seek(SeekFrom::Current(relative))?;
+        this.pending_seek = Some(new_pos);
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        ready!(Pin::new(&mut this.inner).poll_complete(cx))?;
+        if let Some(new_pos) = this.pending_seek.take() {
+            this.pos = new_pos;
+        }
+        Poll::Ready(Ok(this.pos - this.start))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,108 @@
+use super::SubReader;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+/// Wraps a [SubReader] and forwards every byte it reads to a secondary [Write], but tees each
+/// source byte at most once even when the consumer seeks backward and re-reads.
+///
+/// This gives a "capture the bytes I parsed" facility over a seekable sub-region without
+/// duplicating data on re-scan: a parser that backtracks still produces a clean linear copy of
+/// the input it actually consumed.
+#[derive(Debug, Clone)]
+pub struct TeeSubReader<R, W> {
+    reader: SubReader<R>,
+    sink: W,
+    /// Highest position (relative to the reader's `start`) teed to the sink so far.
+    tee_high_water: u64,
+}
+
+impl<R, W> TeeSubReader<R, W> {
+    pub fn new(reader: SubReader<R>, sink: W) -> Self {
+        TeeSubReader {
+            reader,
+            sink,
+            tee_high_water: 0,
+        }
+    }
+
+    pub fn inner(&self) -> &SubReader<R> {
+        &self.reader
+    }
+
+    pub fn sink(&self) -> &W {
+        &self.sink
+    }
+
+    pub fn into_inner(self) -> (SubReader<R>, W) {
+        (self.reader, self.sink)
+    }
+}
+
+impl<R: Read, W: Write> Read for TeeSubReader<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let p = self.reader.relative_pos();
+        let n = self.reader.read(buf)?;
+        if n > 0 {
+            let end = p + n as u64;
+            let tee_from = p.max(self.tee_high_water);
+            if tee_from < end {
+                let skip = (tee_from - p) as usize;
+                self.sink.write_all(&buf[skip..n])?;
+            }
+            self.tee_high_water = self.tee_high_water.max(end);
+        }
+        Ok(n)
+    }
+}
+
+impl<R: Seek, W> Seek for TeeSubReader<R, W> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.reader.seek(pos)
+    }
+
+    /// Infallible.
+    fn stream_position(&mut self) -> io::Result<u64> {
+        self.reader.stream_position()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_tee_subreader_linear() {
+        let data: Vec<u8> = (0..10).collect();
+        let reader = SubReader::new_seek(Cursor::new(data), SeekFrom::Start(2), 6).unwrap();
+        let mut tee = TeeSubReader::new(reader, Vec::new());
+
+        let mut buf = [0u8; 3];
+        tee.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [2, 3, 4]);
+        tee.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [5, 6, 7]);
+
+        let (_, sink) = tee.into_inner();
+        assert_eq!(sink, vec![2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_tee_subreader_backtrack_no_duplication() {
+        let data: Vec<u8> = (0..10).collect();
+        let reader = SubReader::new_seek(Cursor::new(data), SeekFrom::Start(0), 6).unwrap();
+        let mut tee = TeeSubReader::new(reader, Vec::new());
+
+        let mut buf = [0u8; 4];
+        tee.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2, 3]);
+
+        // Backtrack and re-read bytes already teed, then advance past the high-water mark.
+        tee.seek(SeekFrom::Start(1)).unwrap();
+        let mut buf = [0u8; 5];
+        tee.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [1, 2, 3, 4, 5]);
+
+        let (_, sink) = tee.into_inner();
+        assert_eq!(sink, vec![0, 1, 2, 3, 4, 5]);
+    }
+}
@@ -4,6 +4,14 @@ use std::{
     ops::Deref,
 };
 
+#[cfg(feature = "tokio")]
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncSeek, AsyncWrite};
+
 /// Implements [Write] and [Seek] for a portion of the inner type,
 /// where that inner type implements those traits.
 #[derive(Debug, Clone)]
@@ -13,6 +21,9 @@ pub struct SubWriter<W> {
     end: u64,
     pos: u64,
     write_beyond: bool,
+    /// Target of an in-flight [AsyncSeek], set by `start_seek` and consumed by `poll_complete`.
+    #[cfg(feature = "tokio")]
+    pending_seek: Option<u64>,
 }
 
 impl<W: Seek> SubWriter<W> {
@@ -47,6 +58,9 @@ impl<W: Write> Write for SubWriter<W> {
             written
         };
         self.pos += bytes_written;
+        if self.write_beyond {
+            self.end = self.end.max(self.pos);
+        }
         Ok(bytes_written as usize)
     }
 
@@ -82,6 +96,8 @@ impl<W> SubWriter<W> {
             end: pos + length,
             pos,
             write_beyond: false,
+            #[cfg(feature = "tokio")]
+            pending_seek: None,
         }
     }
 
@@ -95,6 +111,15 @@ impl<W> SubWriter<W> {
     pub fn inner_stream_position(&self) -> u64 {
         self.pos
     }
+
+    /// How far the region has actually grown, i.e. the current `pos` or `end`, whichever is
+    /// greater, relative to `start`.
+    ///
+    /// With [SubWriter::write_beyond] set, every write grows `end` to at least `pos`, so after
+    /// writing this is also the position that `SeekFrom::End(0)` reports.
+    pub fn written_len(&self) -> u64 {
+        self.pos.max(self.end) - self.start
+    }
 }
 
 impl<W: Seek> Seek for SubWriter<W> {
@@ -121,6 +146,73 @@ impl<W: Seek> Seek for SubWriter<W> {
     }
 }
 
+#[cfg(feature = "tokio")]
+impl<W: AsyncWrite + Unpin> AsyncWrite for SubWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let to_write = if this.write_beyond {
+            buf
+        } else {
+            if this.pos >= this.end {
+                return Poll::Ready(Ok(0));
+            }
+            let max_write = (this.end - this.pos) as usize;
+            &buf[..buf.len().min(max_write)]
+        };
+        let written = ready!(Pin::new(&mut this.inner).poll_write(cx, to_write))?;
+        if !this.write_beyond && written as u64 + this.pos > this.end {
+            this.end = this.pos;
+        }
+        this.pos += written as u64;
+        if this.write_beyond {
+            this.end = this.end.max(this.pos);
+        }
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: AsyncSeek + Unpin> AsyncSeek for SubWriter<W> {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+        let new_pos = match position {
+            SeekFrom::Start(offset) => this.start + offset,
+            SeekFrom::End(offset) => map_seek_oob(this.end.checked_add_signed(offset))?,
+            SeekFrom::Current(offset) => map_seek_oob(this.pos.checked_add_signed(offset))?,
+        };
+        if new_pos < this.start {
+            return seek_oob();
+        }
+        let relative = (new_pos as i64).checked_sub(this.pos as i64).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Seek position out of bounds")
+        })?;
+        Pin::new(&mut this.inner).start_seek(SeekFrom::Current(relative))?;
+        this.pending_seek = Some(new_pos);
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        ready!(Pin::new(&mut this.inner).poll_complete(cx))?;
+        if let Some(new_pos) = this.pending_seek.take() {
+            this.pos = new_pos;
+        }
+        Poll::Ready(Ok(this.pos - this.start))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +230,20 @@ mod tests {
         let result = sub_writer.into_inner().into_inner();
         assert_eq!(&result, &[0, 1, 2, 3, 4, 0, 1, 2, 8, 9]);
     }
+
+    #[test]
+    fn test_subwriter_write_beyond_high_water() {
+        let cursor = Cursor::new(Vec::<u8>::new());
+        let mut sub_writer = SubWriter::new_seek(cursor, SeekFrom::Start(0), 3)
+            .unwrap()
+            .write_beyond(true);
+
+        assert_eq!(sub_writer.write(&[0, 1, 2, 3, 4]).unwrap(), 5);
+        assert_eq!(sub_writer.written_len(), 5);
+
+        assert_eq!(sub_writer.seek(SeekFrom::End(0)).unwrap(), 5);
+
+        assert_eq!(sub_writer.write(&[5, 6]).unwrap(), 2);
+        assert_eq!(sub_writer.written_len(), 7);
+    }
 }